@@ -0,0 +1,531 @@
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Response, StdResult, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use registry::msg::{QueryMsg as RegistryQueryMsg, ToolResponse};
+use registry::state::PaymentAsset;
+
+use crate::error::ContractError;
+use crate::msg::{Cw20HookMsg, EscrowResponse, ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+use crate::state::{Config, Escrow, CONFIG, ESCROWS, NEXT_ESCROW_ID};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:escrow";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Basis points denominator: fee_bps is out of 10_000
+const MAX_FEE_BPS: u16 = 10_000;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidFeeBps {});
+    }
+
+    let registry_addr = deps.api.addr_validate(&msg.registry_addr)?;
+    let treasury = deps.api.addr_validate(&msg.treasury)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            registry_addr,
+            frozen: false,
+            fee_bps: msg.fee_bps,
+            treasury,
+        },
+    )?;
+    NEXT_ESCROW_ID.save(deps.storage, &0u64)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_not_frozen(deps.as_ref())?;
+
+    match msg {
+        ExecuteMsg::LockFunds {
+            tool_id,
+            max_fee,
+            expires,
+            auth_token,
+            arbiter,
+        } => execute_lock_funds(deps, info, tool_id, max_fee, expires, auth_token, arbiter),
+        ExecuteMsg::Receive(wrapper) => execute_receive(deps, info, wrapper),
+        ExecuteMsg::Release {
+            escrow_id,
+            usage_fee,
+        } => execute_release(deps, env, info, escrow_id, usage_fee),
+        ExecuteMsg::Finalize { escrow_id } => execute_finalize(deps, info, escrow_id),
+        ExecuteMsg::RefundExpired { escrow_id } => execute_refund_expired(deps, env, info, escrow_id),
+        ExecuteMsg::Dispute { escrow_id } => execute_dispute(deps, info, escrow_id),
+        ExecuteMsg::Approve {
+            escrow_id,
+            usage_fee,
+        } => execute_approve(deps, info, escrow_id, usage_fee),
+    }
+}
+
+fn assert_not_frozen(deps: Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.frozen {
+        return Err(ContractError::Frozen {});
+    }
+    Ok(())
+}
+
+/// Looks up a tool's metadata from the Registry contract.
+fn query_tool(deps: Deps, tool_id: &str) -> Result<ToolResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let tool: Option<ToolResponse> = deps.querier.query_wasm_smart(
+        config.registry_addr,
+        &RegistryQueryMsg::GetTool {
+            tool_id: tool_id.to_string(),
+        },
+    )?;
+    tool.ok_or(ContractError::ToolNotFound {})
+}
+
+fn sent_amount(funds: &[Coin], denom: &str) -> Uint128 {
+    funds
+        .iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default()
+}
+
+// LockFunds handler implementation (native denom path)
+#[allow(clippy::too_many_arguments)]
+pub fn execute_lock_funds(
+    deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    max_fee: Uint128,
+    expires: u64,
+    auth_token: String,
+    arbiter: Option<String>,
+) -> Result<Response, ContractError> {
+    let tool = query_tool(deps.as_ref(), &tool_id)?;
+    if !tool.is_active {
+        return Err(ContractError::ToolInactive {});
+    }
+    if let PaymentAsset::Cw20(_) = tool.payment_asset {
+        return Err(ContractError::RequiresCw20Payment {});
+    }
+
+    let sent = sent_amount(&info.funds, &tool.denom);
+    if sent != max_fee {
+        return Err(ContractError::InvalidFunds {
+            expected: max_fee,
+            sent,
+            denom: tool.denom.clone(),
+        });
+    }
+
+    let arbiter = arbiter.map(|addr| deps.api.addr_validate(&addr)).transpose()?;
+    let escrow_id = save_escrow(
+        deps,
+        info.sender,
+        tool,
+        tool_id.clone(),
+        max_fee,
+        expires,
+        auth_token,
+        None,
+        arbiter,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "lock_funds")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("max_fee", max_fee.to_string())
+        .add_attribute("expires", expires.to_string()))
+}
+
+// Receive handler implementation (CW20 payment path)
+pub fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_json(&wrapper.msg)? {
+        Cw20HookMsg::LockFunds {
+            tool_id,
+            max_fee,
+            expires,
+            auth_token,
+            arbiter,
+        } => {
+            let tool = query_tool(deps.as_ref(), &tool_id)?;
+            if !tool.is_active {
+                return Err(ContractError::ToolInactive {});
+            }
+
+            let cw20_contract = match &tool.payment_asset {
+                PaymentAsset::Cw20(addr) => addr.clone(),
+                PaymentAsset::Native(_) => return Err(ContractError::NotCw20Priced {}),
+            };
+            if info.sender != cw20_contract.as_str() {
+                return Err(ContractError::InvalidCw20Sender {
+                    expected: cw20_contract.to_string(),
+                    actual: info.sender.to_string(),
+                });
+            }
+            if wrapper.amount != max_fee {
+                return Err(ContractError::InvalidFunds {
+                    expected: max_fee,
+                    sent: wrapper.amount,
+                    denom: tool.denom.clone(),
+                });
+            }
+
+            let user = deps.api.addr_validate(&wrapper.sender)?;
+            let arbiter = arbiter.map(|addr| deps.api.addr_validate(&addr)).transpose()?;
+            let escrow_id = save_escrow(
+                deps,
+                user,
+                tool,
+                tool_id.clone(),
+                max_fee,
+                expires,
+                auth_token,
+                Some(cw20_contract),
+                arbiter,
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("method", "lock_funds")
+                .add_attribute("escrow_id", escrow_id.to_string())
+                .add_attribute("tool_id", tool_id)
+                .add_attribute("max_fee", max_fee.to_string())
+                .add_attribute("expires", expires.to_string()))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn save_escrow(
+    deps: DepsMut,
+    user: Addr,
+    tool: ToolResponse,
+    tool_id: String,
+    max_fee: Uint128,
+    expires: u64,
+    auth_token: String,
+    cw20_contract: Option<Addr>,
+    arbiter: Option<Addr>,
+) -> Result<u64, ContractError> {
+    let escrow_id = NEXT_ESCROW_ID.load(deps.storage)?;
+    NEXT_ESCROW_ID.save(deps.storage, &(escrow_id + 1))?;
+
+    ESCROWS.save(
+        deps.storage,
+        escrow_id,
+        &Escrow {
+            user,
+            provider: deps.api.addr_validate(&tool.provider)?,
+            tool_id,
+            denom: tool.denom,
+            cw20_contract,
+            max_fee,
+            expires,
+            auth_token,
+            arbiter,
+            disputed: false,
+            released_so_far: Uint128::zero(),
+        },
+    )?;
+
+    Ok(escrow_id)
+}
+
+/// Builds the payout message for `amount`, using a native bank transfer or a CW20 transfer
+/// depending on how the escrow was funded.
+fn payout_msg(escrow: &Escrow, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    match &escrow.cw20_contract {
+        Some(cw20_addr) => Ok(WasmMsg::Execute {
+            contract_addr: cw20_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+        None => Ok(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: escrow.denom.clone(),
+                amount,
+            }],
+        }
+        .into()),
+    }
+}
+
+// Release handler implementation: pays out one milestone draw-down, leaving the escrow open
+// for further Release calls until the provider calls Finalize or it expires.
+pub fn execute_release(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+    usage_fee: Uint128,
+) -> Result<Response, ContractError> {
+    let mut escrow = ESCROWS
+        .may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+
+    if info.sender != escrow.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    if escrow.disputed {
+        return Err(ContractError::Disputed {});
+    }
+    if escrow.is_expired(env.block.height) {
+        return Err(ContractError::Expired {});
+    }
+    if usage_fee > escrow.remaining() {
+        return Err(ContractError::ExceedsMaxFee {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let fee = usage_fee.multiply_ratio(config.fee_bps as u128, MAX_FEE_BPS as u128);
+    let provider_amount = usage_fee - fee;
+
+    let mut messages = vec![payout_msg(&escrow, &escrow.provider, provider_amount)?];
+    if !fee.is_zero() {
+        messages.push(payout_msg(&escrow, &config.treasury, fee)?);
+    }
+
+    escrow.released_so_far += usage_fee;
+    ESCROWS.save(deps.storage, escrow_id, &escrow)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("method", "release")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("usage_fee", usage_fee.to_string())
+        .add_attribute("fee", fee.to_string())
+        .add_attribute("treasury", config.treasury.to_string())
+        .add_attribute("released_so_far", escrow.released_so_far.to_string()))
+}
+
+// Finalize handler implementation: provider-only, closes the escrow and refunds whatever
+// max_fee the milestone Release calls didn't draw down.
+pub fn execute_finalize(
+    deps: DepsMut,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    let escrow = ESCROWS
+        .may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+
+    if info.sender != escrow.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+    if escrow.disputed {
+        return Err(ContractError::Disputed {});
+    }
+
+    let refund = escrow.remaining();
+    let mut messages = vec![];
+    if !refund.is_zero() {
+        messages.push(payout_msg(&escrow, &escrow.user, refund)?);
+    }
+
+    ESCROWS.remove(deps.storage, escrow_id);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("method", "finalize")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("refund", refund.to_string()))
+}
+
+// RefundExpired handler implementation
+pub fn execute_refund_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    let escrow = ESCROWS
+        .may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+
+    if info.sender != escrow.user {
+        return Err(ContractError::Unauthorized {});
+    }
+    if escrow.disputed {
+        return Err(ContractError::Disputed {});
+    }
+    if !escrow.is_expired(env.block.height) {
+        return Err(ContractError::NotExpired {});
+    }
+
+    let refund = escrow.remaining();
+    let message = payout_msg(&escrow, &escrow.user, refund)?;
+    ESCROWS.remove(deps.storage, escrow_id);
+
+    Ok(Response::new()
+        .add_message(message)
+        .add_attribute("method", "refund_expired")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("refund", refund.to_string()))
+}
+
+// Dispute handler implementation
+pub fn execute_dispute(
+    deps: DepsMut,
+    info: MessageInfo,
+    escrow_id: u64,
+) -> Result<Response, ContractError> {
+    let mut escrow = ESCROWS
+        .may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+
+    if info.sender != escrow.user {
+        return Err(ContractError::Unauthorized {});
+    }
+    if escrow.arbiter.is_none() {
+        return Err(ContractError::NoArbiter {});
+    }
+
+    escrow.disputed = true;
+    ESCROWS.save(deps.storage, escrow_id, &escrow)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "dispute")
+        .add_attribute("escrow_id", escrow_id.to_string()))
+}
+
+// Approve handler implementation (arbiter-only resolution of a disputed escrow)
+pub fn execute_approve(
+    deps: DepsMut,
+    info: MessageInfo,
+    escrow_id: u64,
+    usage_fee: Uint128,
+) -> Result<Response, ContractError> {
+    let escrow = ESCROWS
+        .may_load(deps.storage, escrow_id)?
+        .ok_or(ContractError::EscrowNotFound {})?;
+
+    if !escrow.disputed {
+        return Err(ContractError::NotDisputed {});
+    }
+    if Some(&info.sender) != escrow.arbiter.as_ref() {
+        return Err(ContractError::NotArbiter {});
+    }
+    if usage_fee > escrow.remaining() {
+        return Err(ContractError::ExceedsMaxFee {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let fee = usage_fee.multiply_ratio(config.fee_bps as u128, MAX_FEE_BPS as u128);
+    let provider_amount = usage_fee - fee;
+    let refund = escrow.remaining() - usage_fee;
+
+    let mut messages = vec![payout_msg(&escrow, &escrow.provider, provider_amount)?];
+    if !fee.is_zero() {
+        messages.push(payout_msg(&escrow, &config.treasury, fee)?);
+    }
+    if !refund.is_zero() {
+        messages.push(payout_msg(&escrow, &escrow.user, refund)?);
+    }
+
+    ESCROWS.remove(deps.storage, escrow_id);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("method", "approve")
+        .add_attribute("escrow_id", escrow_id.to_string())
+        .add_attribute("usage_fee", usage_fee.to_string())
+        .add_attribute("fee", fee.to_string())
+        .add_attribute("treasury", config.treasury.to_string())
+        .add_attribute("refund", refund.to_string()))
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::Freeze {} => set_frozen(deps, true),
+        SudoMsg::Unfreeze {} => set_frozen(deps, false),
+        SudoMsg::SetFee { fee_bps, treasury } => set_fee(deps, fee_bps, treasury),
+    }
+}
+
+fn set_fee(deps: DepsMut, fee_bps: u16, treasury: String) -> Result<Response, ContractError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidFeeBps {});
+    }
+
+    let treasury = deps.api.addr_validate(&treasury)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    config.fee_bps = fee_bps;
+    config.treasury = treasury.clone();
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_fee")
+        .add_attribute("fee_bps", fee_bps.to_string())
+        .add_attribute("treasury", treasury))
+}
+
+fn set_frozen(deps: DepsMut, frozen: bool) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    config.frozen = frozen;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_frozen")
+        .add_attribute("frozen", frozen.to_string()))
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetEscrow { escrow_id } => query_escrow(deps, escrow_id),
+    }
+}
+
+pub fn query_escrow(deps: Deps, escrow_id: u64) -> StdResult<Binary> {
+    let escrow = ESCROWS.may_load(deps.storage, escrow_id)?;
+
+    match escrow {
+        Some(escrow) => {
+            let response = EscrowResponse {
+                escrow_id,
+                user: escrow.user.to_string(),
+                provider: escrow.provider.to_string(),
+                tool_id: escrow.tool_id,
+                denom: escrow.denom,
+                cw20_contract: escrow.cw20_contract.map(|addr| addr.to_string()),
+                max_fee: escrow.max_fee,
+                released_so_far: escrow.released_so_far,
+                expires: escrow.expires,
+                arbiter: escrow.arbiter.map(|addr| addr.to_string()),
+                disputed: escrow.disputed,
+            };
+            to_json_binary(&response)
+        }
+        None => to_json_binary(&Option::<EscrowResponse>::None),
+    }
+}
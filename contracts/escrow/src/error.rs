@@ -0,0 +1,63 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Escrow not found")]
+    EscrowNotFound {},
+
+    #[error("Tool not found")]
+    ToolNotFound {},
+
+    #[error("Tool is not active")]
+    ToolInactive {},
+
+    #[error("Escrow has already expired")]
+    Expired {},
+
+    #[error("Escrow has not yet expired")]
+    NotExpired {},
+
+    #[error("This release would exceed the escrow's max fee")]
+    ExceedsMaxFee {},
+
+    #[error("Expected {expected}{denom} but sent {sent}{denom}")]
+    InvalidFunds {
+        expected: cosmwasm_std::Uint128,
+        sent: cosmwasm_std::Uint128,
+        denom: String,
+    },
+
+    #[error("Expected a CW20 token transfer from {expected} but received one from {actual}")]
+    InvalidCw20Sender { expected: String, actual: String },
+
+    #[error("This tool is not priced in CW20 tokens")]
+    NotCw20Priced {},
+
+    #[error("This tool is priced in CW20 tokens; lock funds via the Receive/Send payment path instead")]
+    RequiresCw20Payment {},
+
+    #[error("Contract is frozen")]
+    Frozen {},
+
+    #[error("Only the named arbiter may perform this action")]
+    NotArbiter {},
+
+    #[error("Escrow has no arbiter; cannot be disputed")]
+    NoArbiter {},
+
+    #[error("fee_bps must be 10_000 or less")]
+    InvalidFeeBps {},
+
+    #[error("Escrow is under dispute")]
+    Disputed {},
+
+    #[error("Escrow is not under dispute")]
+    NotDisputed {},
+}
@@ -0,0 +1,98 @@
+use cosmwasm_std::Uint128;
+use cw20::Cw20ReceiveMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    pub registry_addr: String,
+    /// Protocol fee skimmed from `Release`, in basis points; must be <= 10_000.
+    pub fee_bps: u16,
+    pub treasury: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Lock native funds for a tool call, priced in the tool's registered native denom.
+    /// An optional `arbiter` can later resolve a `Dispute` instead of the escrow expiring.
+    LockFunds {
+        tool_id: String,
+        max_fee: Uint128,
+        expires: u64,
+        auth_token: String,
+        arbiter: Option<String>,
+    },
+    /// CW20 entry point: the CW20 contract calls this on our behalf when a user sends tokens
+    /// via `Cw20ExecuteMsg::Send`. `msg` must deserialize into `Cw20HookMsg`.
+    Receive(Cw20ReceiveMsg),
+    /// Pays `usage_fee`, less the protocol fee sent to the treasury, to the provider
+    /// immediately as a milestone draw-down; may be called
+    /// repeatedly as long as the cumulative released amount stays within `max_fee`. The escrow
+    /// stays open for further `Release` calls until `Finalize` or expiry.
+    Release {
+        escrow_id: u64,
+        usage_fee: Uint128,
+    },
+    /// Provider-only: closes out the escrow, refunding whatever remains of `max_fee` to the
+    /// user after all `Release` draw-downs.
+    Finalize {
+        escrow_id: u64,
+    },
+    RefundExpired {
+        escrow_id: u64,
+    },
+    /// User-initiated: blocks `Release`/`RefundExpired` until the named arbiter calls `Approve`.
+    Dispute {
+        escrow_id: u64,
+    },
+    /// Arbiter-only: settles a disputed escrow, paying `usage_fee` (less the protocol fee, as
+    /// with `Release`) to the provider and refunding the remainder to the user. A `usage_fee`
+    /// above whatever of `max_fee` hasn't already been released is rejected.
+    Approve {
+        escrow_id: u64,
+        usage_fee: Uint128,
+    },
+}
+
+/// The payload carried in `Cw20ReceiveMsg::msg`, mirroring `ExecuteMsg::LockFunds`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20HookMsg {
+    LockFunds {
+        tool_id: String,
+        max_fee: Uint128,
+        expires: u64,
+        auth_token: String,
+        arbiter: Option<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    GetEscrow { escrow_id: u64 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    Freeze {},
+    Unfreeze {},
+    SetFee { fee_bps: u16, treasury: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EscrowResponse {
+    pub escrow_id: u64,
+    pub user: String,
+    pub provider: String,
+    pub tool_id: String,
+    pub denom: String,
+    pub cw20_contract: Option<String>,
+    pub max_fee: Uint128,
+    pub released_so_far: Uint128,
+    pub expires: u64,
+    pub arbiter: Option<String>,
+    pub disputed: bool,
+}
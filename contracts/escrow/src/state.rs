@@ -0,0 +1,48 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub registry_addr: Addr,
+    pub frozen: bool,
+    /// Protocol fee skimmed from `Release`, in basis points (1/100 of a percent); max 10_000.
+    pub fee_bps: u16,
+    pub treasury: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Escrow {
+    pub user: Addr,
+    pub provider: Addr,
+    pub tool_id: String,
+    /// Native denom the escrow was locked in; unused when `cw20_contract` is set.
+    pub denom: String,
+    /// Set when the tool is priced in a CW20 token; `denom` is then ignored.
+    pub cw20_contract: Option<Addr>,
+    pub max_fee: Uint128,
+    pub expires: u64,
+    pub auth_token: String,
+    /// When set, disputes on this escrow are resolved by this address instead of expiring.
+    pub arbiter: Option<Addr>,
+    /// Set by `Dispute`; blocks `Release`/`RefundExpired` until the arbiter calls `Approve`.
+    pub disputed: bool,
+    /// Cumulative amount paid to the provider across all `Release` calls so far.
+    pub released_so_far: Uint128,
+}
+
+impl Escrow {
+    pub fn is_expired(&self, current_height: u64) -> bool {
+        current_height > self.expires
+    }
+
+    /// The portion of `max_fee` not yet paid out to the provider.
+    pub fn remaining(&self) -> Uint128 {
+        self.max_fee - self.released_so_far
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const ESCROWS: Map<u64, Escrow> = Map::new("escrows");
+pub const NEXT_ESCROW_ID: Item<u64> = Item::new("next_escrow_id");
@@ -0,0 +1,130 @@
+//! # CW20 Payment Test
+//!
+//! This module tests the CW20 token payment rail: a tool priced in a CW20
+//! token is locked via `Cw20ExecuteMsg::Send` instead of native funds, and
+//! `Release`/`Finalize` pay out via `Cw20ExecuteMsg::Transfer`.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Sending the registered CW20 token with a `LockFunds` hook creates an escrow
+//! 2. A `Send` from a CW20 contract other than the one registered for the tool is rejected
+//! 3. `Release`/`Finalize` pay the provider/user in the locked CW20 token
+
+use cw_multi_test::Executor;
+use cosmwasm_std::{to_json_binary, Addr, Uint128};
+use cw20_base::msg::QueryMsg as Cw20BaseQueryMsg;
+
+use crate::error::ContractError;
+use crate::msg::Cw20HookMsg;
+use crate::tests::setup_contract::{
+    setup_contracts, setup_cw20, register_cw20_tool, lock_funds_cw20, release, finalize,
+    DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, DEFAULT_TTL, USER, PROVIDER,
+};
+
+fn cw20_balance(contracts: &cw_multi_test::App, cw20_addr: &str, holder: &str) -> Uint128 {
+    let response: cw20::BalanceResponse = contracts
+        .wrap()
+        .query_wasm_smart(
+            cw20_addr,
+            &Cw20BaseQueryMsg::Balance {
+                address: holder.to_string(),
+            },
+        )
+        .unwrap();
+    response.balance
+}
+
+/// # Test: CW20-Priced Tool Lock, Release, and Finalize
+///
+/// ## Test Steps:
+///
+/// 1. Set up the contracts and a CW20 token, minting it to the user
+/// 2. Register a tool priced in that token
+/// 3. Lock funds by sending the token via `Cw20ExecuteMsg::Send`
+/// 4. Release a usage fee and finalize, verifying CW20 balances throughout
+#[test]
+fn test_cw20_lock_release_finalize() {
+    let mut contracts = setup_contracts();
+
+    let cw20_addr = setup_cw20(&mut contracts, USER, 10 * DEFAULT_MAX_FEE);
+    register_cw20_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, &cw20_addr, PROVIDER)
+        .unwrap();
+
+    let escrow_id = lock_funds_cw20(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "cw20_payment_test".to_string(),
+        USER,
+        &cw20_addr,
+    )
+    .unwrap();
+
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let user_addr = contracts.app.api().addr_make(USER);
+
+    // The escrow contract itself now holds the locked tokens.
+    assert_eq!(
+        cw20_balance(&contracts.app, &cw20_addr, &contracts.escrow_addr),
+        Uint128::new(DEFAULT_MAX_FEE)
+    );
+
+    let usage_fee = DEFAULT_MAX_FEE / 2;
+    release(&mut contracts, escrow_id, usage_fee, PROVIDER).unwrap();
+    assert_eq!(
+        cw20_balance(&contracts.app, &cw20_addr, provider_addr.as_str()),
+        Uint128::new(usage_fee)
+    );
+
+    finalize(&mut contracts, escrow_id, PROVIDER).unwrap();
+    assert_eq!(
+        cw20_balance(&contracts.app, &cw20_addr, user_addr.as_str()),
+        Uint128::new(10 * DEFAULT_MAX_FEE - usage_fee)
+    );
+}
+
+/// # Test: A Send From an Unregistered CW20 Contract Is Rejected
+///
+/// ## Test Steps:
+///
+/// 1. Register a tool priced in one CW20 token
+/// 2. Send a `LockFunds` hook from a *different* CW20 token
+/// 3. Verify the escrow rejects it with `InvalidCw20Sender`
+#[test]
+fn test_cw20_wrong_token_rejected() {
+    let mut contracts = setup_contracts();
+
+    let cw20_addr = setup_cw20(&mut contracts, USER, DEFAULT_MAX_FEE);
+    let other_cw20_addr = setup_cw20(&mut contracts, USER, DEFAULT_MAX_FEE);
+    register_cw20_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, &cw20_addr, PROVIDER)
+        .unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let expires = contracts.app.block_info().height + DEFAULT_TTL;
+    let hook_msg = Cw20HookMsg::LockFunds {
+        tool_id: DEFAULT_TOOL_ID.to_string(),
+        max_fee: Uint128::new(DEFAULT_MAX_FEE),
+        expires,
+        auth_token: "cw20_wrong_token_test".to_string(),
+        arbiter: None,
+    };
+
+    let result = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&other_cw20_addr),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: contracts.escrow_addr.clone(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+            msg: to_json_binary(&hook_msg).unwrap(),
+        },
+        &[],
+    );
+
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::InvalidCw20Sender { .. }) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
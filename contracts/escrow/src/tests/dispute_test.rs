@@ -0,0 +1,245 @@
+//! # Dispute Resolution Test
+//!
+//! This module tests the arbiter-mediated dispute flow: a user can dispute an
+//! escrow before it is released or expires, which blocks the normal
+//! `Release`/`RefundExpired` paths until the named arbiter settles it.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Disputing an escrow blocks `Release` and `RefundExpired`
+//! 2. Only the named arbiter may `Approve` a disputed escrow
+//! 3. `Approve` splits funds between the provider and the user, like `Release`
+//! 4. An escrow locked with no arbiter cannot be disputed
+//! 5. `Approve` is rejected on an escrow that was never disputed
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::tests::setup_contract::{
+    setup_contracts, register_tool, lock_funds, NEUTRON, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE,
+    DEFAULT_TTL, USER, PROVIDER,
+};
+
+const ARBITER: &str = "arbiter";
+
+/// # Test: Dispute Blocks Settlement Until the Arbiter Approves
+///
+/// ## Test Steps:
+///
+/// 1. Set up the contracts and register a tool
+/// 2. Lock funds naming an arbiter
+/// 3. Dispute the escrow as the user
+/// 4. Verify the provider can no longer `Release`
+/// 5. Verify only the arbiter can `Approve`, and that it splits funds correctly
+#[test]
+fn test_dispute_then_arbiter_approves() {
+    let mut contracts = setup_contracts();
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let arbiter_addr = contracts.app.api().addr_make(ARBITER);
+
+    let response = contracts
+        .app
+        .execute_contract(
+            user_addr.clone(),
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::LockFunds {
+                tool_id: DEFAULT_TOOL_ID.to_string(),
+                max_fee: Uint128::new(DEFAULT_MAX_FEE),
+                expires: contracts.app.block_info().height + DEFAULT_TTL,
+                auth_token: "dispute_test".to_string(),
+                arbiter: Some(arbiter_addr.to_string()),
+            },
+            &[Coin {
+                denom: NEUTRON.to_string(),
+                amount: Uint128::new(DEFAULT_MAX_FEE),
+            }],
+        )
+        .unwrap();
+    let escrow_id: u64 = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "escrow_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+
+    // User disputes the escrow
+    contracts
+        .app
+        .execute_contract(
+            user_addr,
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::Dispute { escrow_id },
+            &[],
+        )
+        .unwrap();
+
+    // Provider can no longer release
+    let result = contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Release {
+            escrow_id,
+            usage_fee: Uint128::new(DEFAULT_MAX_FEE / 2),
+        },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Disputed {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    // A non-arbiter cannot approve
+    let not_arbiter = contracts.app.api().addr_make("not_the_arbiter");
+    let result = contracts.app.execute_contract(
+        not_arbiter,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Approve {
+            escrow_id,
+            usage_fee: Uint128::new(DEFAULT_MAX_FEE / 2),
+        },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::NotArbiter {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    // The arbiter approves, splitting funds between provider and user
+    let usage_fee = Uint128::new(DEFAULT_MAX_FEE / 2);
+    contracts
+        .app
+        .execute_contract(
+            arbiter_addr,
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::Approve {
+                escrow_id,
+                usage_fee,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let provider_balance = contracts
+        .app
+        .wrap()
+        .query_balance(contracts.app.api().addr_make(PROVIDER), NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(provider_balance, usage_fee);
+
+    // The user started with 10 * max_fee and got back whatever of max_fee the provider didn't receive
+    let user_balance = contracts
+        .app
+        .wrap()
+        .query_balance(contracts.app.api().addr_make(USER), NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(user_balance, Uint128::new(10 * DEFAULT_MAX_FEE) - usage_fee);
+}
+
+/// # Test: Disputing an Escrow With No Arbiter Is Rejected
+///
+/// An escrow locked without naming an arbiter has no one who could ever call
+/// `Approve`, so letting it be disputed would freeze the funds permanently.
+#[test]
+fn test_dispute_without_arbiter_fails() {
+    let mut contracts = setup_contracts();
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        "dispute_no_arbiter_test".to_string(),
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+    .unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let result = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Dispute { escrow_id },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::NoArbiter {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
+
+/// # Test: Approve Without a Prior Dispute Is Rejected
+///
+/// The arbiter is only meant to settle an escrow *after* the user disputes it;
+/// calling `Approve` up front would let the arbiter override the provider's
+/// normal `Release`/milestone flow and the user's `RefundExpired` path outright.
+#[test]
+fn test_approve_without_dispute_fails() {
+    let mut contracts = setup_contracts();
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let arbiter_addr = contracts.app.api().addr_make(ARBITER);
+
+    let response = contracts
+        .app
+        .execute_contract(
+            user_addr,
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::LockFunds {
+                tool_id: DEFAULT_TOOL_ID.to_string(),
+                max_fee: Uint128::new(DEFAULT_MAX_FEE),
+                expires: contracts.app.block_info().height + DEFAULT_TTL,
+                auth_token: "approve_without_dispute_test".to_string(),
+                arbiter: Some(arbiter_addr.to_string()),
+            },
+            &[Coin {
+                denom: NEUTRON.to_string(),
+                amount: Uint128::new(DEFAULT_MAX_FEE),
+            }],
+        )
+        .unwrap();
+    let escrow_id: u64 = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "escrow_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+
+    let result = contracts.app.execute_contract(
+        arbiter_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Approve {
+            escrow_id,
+            usage_fee: Uint128::new(DEFAULT_MAX_FEE / 2),
+        },
+        &[],
+    );
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::NotDisputed {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+}
@@ -7,7 +7,8 @@
 //! 
 //! This test verifies that:
 //! 1. When the contract is frozen via sudo, operations are rejected
-//! 2. LockFunds, Release, and RefundExpired cannot be executed when frozen
+//! 2. LockFunds, Release, RefundExpired, Dispute, Approve, and Finalize cannot be executed
+//!    when frozen
 //! 3. The correct error is returned for operations on a frozen contract
 
 use cosmwasm_std::{Addr, Coin, Uint128, to_json_binary};
@@ -77,6 +78,7 @@ fn test_frozen_contract() {
             max_fee: Uint128::new(DEFAULT_MAX_FEE),
             expires: contracts.app.block_info().height + DEFAULT_TTL,
             auth_token: "another_token".into(),
+            arbiter: None,
         },
         &[Coin {
             denom: NEUTRON.to_string(),
@@ -95,7 +97,7 @@ fn test_frozen_contract() {
     // SECTION 2: Test releasing escrow on frozen contract
     let provider_addr = contracts.app.api().addr_make(PROVIDER);
     let result = contracts.app.execute_contract(
-        provider_addr,
+        provider_addr.clone(),
         Addr::unchecked(&contracts.escrow_addr),
         &ExecuteMsg::Release {
             escrow_id,
@@ -134,4 +136,56 @@ fn test_frozen_contract() {
         Ok(err) => panic!("Unexpected error: {:?}", err),
         Err(err) => panic!("Wrong error type: {:?}", err),
     }
+
+    // SECTION 4: Test disputing an escrow on frozen contract
+    let result = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Dispute { escrow_id },
+        &[],
+    );
+
+    // Verify operation failed with Frozen error
+    assert!(result.is_err());
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Frozen {}) => {}, // Expected error
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    // SECTION 5: Test Approve on frozen contract (the frozen check runs before the
+    // sender is compared with the escrow's arbiter)
+    let result = contracts.app.execute_contract(
+        provider_addr.clone(),
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Approve {
+            escrow_id,
+            usage_fee: Uint128::new(DEFAULT_MAX_FEE / 2),
+        },
+        &[],
+    );
+
+    // Verify operation failed with Frozen error
+    assert!(result.is_err());
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Frozen {}) => {}, // Expected error
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    // SECTION 6: Test finalizing an escrow on frozen contract
+    let result = contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Finalize { escrow_id },
+        &[],
+    );
+
+    // Verify operation failed with Frozen error
+    assert!(result.is_err());
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::Frozen {}) => {}, // Expected error
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
 }
@@ -0,0 +1,95 @@
+//! # Milestone Release Test
+//!
+//! This module tests that a provider can draw down an escrow's `max_fee` in
+//! several `Release` calls instead of a single shot, and that the escrow is
+//! only closed out once the provider calls `Finalize`.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Multiple `Release` calls pay the provider each increment immediately
+//! 2. A `Release` whose cumulative total would exceed `max_fee` is rejected
+//! 3. `Finalize` refunds only the unreleased balance to the user
+
+use cosmwasm_std::{Coin, Uint128};
+use crate::error::ContractError;
+use crate::tests::setup_contract::{
+    setup_contracts, register_tool, lock_funds, release, finalize, NEUTRON, DEFAULT_TOOL_ID,
+    DEFAULT_MAX_FEE, USER, PROVIDER, DEFAULT_TTL,
+};
+
+/// # Test: Repeated Release Calls Draw Down a Single Escrow
+///
+/// ## Test Steps:
+///
+/// 1. Set up the contracts and lock funds for one escrow
+/// 2. Release two separate milestones to the provider
+/// 3. Verify a release that would exceed `max_fee` is rejected
+/// 4. Finalize the escrow and verify the user is refunded only the unreleased balance
+#[test]
+fn test_multiple_releases_then_finalize() {
+    let mut contracts = setup_contracts();
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let auth_token = "milestone_release_test".to_string();
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        auth_token,
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+    .unwrap();
+
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let user_addr = contracts.app.api().addr_make(USER);
+
+    let first_milestone = DEFAULT_MAX_FEE / 4;
+    release(&mut contracts, escrow_id, first_milestone, PROVIDER).unwrap();
+
+    let second_milestone = DEFAULT_MAX_FEE / 4;
+    release(&mut contracts, escrow_id, second_milestone, PROVIDER).unwrap();
+
+    let provider_balance = contracts
+        .app
+        .wrap()
+        .query_balance(provider_addr, NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(provider_balance.u128(), first_milestone + second_milestone);
+
+    // A release that would push the cumulative total past max_fee is rejected.
+    let result = release(&mut contracts, escrow_id, DEFAULT_MAX_FEE, PROVIDER);
+    match result.unwrap_err().downcast::<ContractError>() {
+        Ok(ContractError::ExceedsMaxFee {}) => {}
+        Ok(err) => panic!("Unexpected error: {:?}", err),
+        Err(err) => panic!("Wrong error type: {:?}", err),
+    }
+
+    let pre_finalize_user_balance = contracts
+        .app
+        .wrap()
+        .query_balance(user_addr.to_string(), NEUTRON)
+        .unwrap()
+        .amount;
+
+    finalize(&mut contracts, escrow_id, PROVIDER).unwrap();
+
+    let post_finalize_user_balance = contracts
+        .app
+        .wrap()
+        .query_balance(user_addr.to_string(), NEUTRON)
+        .unwrap()
+        .amount;
+    let expected_refund = DEFAULT_MAX_FEE - first_milestone - second_milestone;
+    assert_eq!(
+        (post_finalize_user_balance - pre_finalize_user_balance).u128(),
+        expected_refund
+    );
+}
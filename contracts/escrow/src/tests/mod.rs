@@ -0,0 +1,8 @@
+mod setup_contract;
+
+mod cw20_payment_test;
+mod dispute_test;
+mod expired_escrow_refund_test;
+mod frozen_contract_test;
+mod milestone_release_test;
+mod protocol_fee_test;
@@ -0,0 +1,192 @@
+//! # Protocol Fee Test
+//!
+//! This module tests that `Release` skims a configurable protocol fee into
+//! the treasury before paying the rest of each milestone to the provider, and
+//! that `Finalize` refunds whatever of `max_fee` was never released.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. `Release` sends `usage_fee * fee_bps / 10_000` to the treasury
+//! 2. The provider receives the rest of `usage_fee`
+//! 3. `Finalize` refunds `max_fee - usage_fee` to the user, unaffected by the fee
+//! 4. `Approve` (the arbiter-mediated settlement path) skims the same fee as `Release`
+
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use crate::msg::ExecuteMsg;
+use crate::tests::setup_contract::{
+    setup_contracts_with_fee, register_tool, lock_funds, release, finalize, NEUTRON,
+    DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, USER, PROVIDER, DEFAULT_TTL, TREASURY,
+};
+
+/// # Test: Release Splits Funds Across Provider and Treasury, Finalize Refunds the Rest
+///
+/// ## Test Steps:
+///
+/// 1. Set up the contracts with a 500 bps (5%) protocol fee
+/// 2. Register a tool, lock funds, and release a usage fee
+/// 3. Verify the exact balance split between the provider and the treasury
+/// 4. Finalize the escrow and verify the user is refunded the unreleased balance
+#[test]
+fn test_release_skims_protocol_fee() {
+    const FEE_BPS: u16 = 500; // 5%
+
+    let mut contracts = setup_contracts_with_fee(FEE_BPS);
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let auth_token = "protocol_fee_test".to_string();
+    let escrow_id = lock_funds(
+        &mut contracts,
+        DEFAULT_TOOL_ID,
+        DEFAULT_MAX_FEE,
+        DEFAULT_TTL,
+        auth_token,
+        USER,
+        &[Coin {
+            denom: NEUTRON.to_string(),
+            amount: Uint128::new(DEFAULT_MAX_FEE),
+        }],
+    )
+    .unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let provider_addr = contracts.app.api().addr_make(PROVIDER);
+    let treasury_addr = contracts.app.api().addr_make(TREASURY);
+
+    let pre_release_user_balance = contracts
+        .app
+        .wrap()
+        .query_balance(user_addr.to_string(), NEUTRON)
+        .unwrap()
+        .amount;
+
+    let usage_fee = DEFAULT_MAX_FEE / 2;
+    release(&mut contracts, escrow_id, usage_fee, PROVIDER).unwrap();
+
+    let expected_fee = Uint128::new(usage_fee).multiply_ratio(FEE_BPS as u128, 10_000u128);
+    let expected_provider_amount = Uint128::new(usage_fee) - expected_fee;
+    let expected_refund = Uint128::new(DEFAULT_MAX_FEE - usage_fee);
+
+    let provider_balance = contracts
+        .app
+        .wrap()
+        .query_balance(provider_addr, NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(provider_balance, expected_provider_amount);
+
+    let treasury_balance = contracts
+        .app
+        .wrap()
+        .query_balance(treasury_addr, NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(treasury_balance, expected_fee);
+
+    finalize(&mut contracts, escrow_id, PROVIDER).unwrap();
+
+    let post_finalize_user_balance = contracts
+        .app
+        .wrap()
+        .query_balance(user_addr.to_string(), NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(
+        post_finalize_user_balance - pre_release_user_balance,
+        expected_refund
+    );
+}
+
+/// # Test: Approve Skims the Same Protocol Fee as Release
+///
+/// ## Test Steps:
+///
+/// 1. Set up the contracts with a 500 bps (5%) protocol fee
+/// 2. Lock funds naming an arbiter, then dispute the escrow
+/// 3. Have the arbiter approve a usage fee
+/// 4. Verify the fee split across the provider and treasury matches `Release`'s
+#[test]
+fn test_approve_skims_protocol_fee() {
+    const FEE_BPS: u16 = 500; // 5%
+    const ARBITER: &str = "arbiter";
+
+    let mut contracts = setup_contracts_with_fee(FEE_BPS);
+
+    register_tool(&mut contracts, DEFAULT_TOOL_ID, DEFAULT_MAX_FEE, PROVIDER).unwrap();
+
+    let user_addr = contracts.app.api().addr_make(USER);
+    let arbiter_addr = contracts.app.api().addr_make(ARBITER);
+
+    let response = contracts
+        .app
+        .execute_contract(
+            user_addr.clone(),
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::LockFunds {
+                tool_id: DEFAULT_TOOL_ID.to_string(),
+                max_fee: Uint128::new(DEFAULT_MAX_FEE),
+                expires: contracts.app.block_info().height + DEFAULT_TTL,
+                auth_token: "approve_protocol_fee_test".to_string(),
+                arbiter: Some(arbiter_addr.to_string()),
+            },
+            &[Coin {
+                denom: NEUTRON.to_string(),
+                amount: Uint128::new(DEFAULT_MAX_FEE),
+            }],
+        )
+        .unwrap();
+    let escrow_id: u64 = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "escrow_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+
+    contracts
+        .app
+        .execute_contract(
+            user_addr,
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::Dispute { escrow_id },
+            &[],
+        )
+        .unwrap();
+
+    let usage_fee = DEFAULT_MAX_FEE / 2;
+    contracts
+        .app
+        .execute_contract(
+            arbiter_addr,
+            Addr::unchecked(&contracts.escrow_addr),
+            &ExecuteMsg::Approve {
+                escrow_id,
+                usage_fee: Uint128::new(usage_fee),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let expected_fee = Uint128::new(usage_fee).multiply_ratio(FEE_BPS as u128, 10_000u128);
+    let expected_provider_amount = Uint128::new(usage_fee) - expected_fee;
+
+    let provider_balance = contracts
+        .app
+        .wrap()
+        .query_balance(contracts.app.api().addr_make(PROVIDER), NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(provider_balance, expected_provider_amount);
+
+    let treasury_balance = contracts
+        .app
+        .wrap()
+        .query_balance(contracts.app.api().addr_make(TREASURY), NEUTRON)
+        .unwrap()
+        .amount;
+    assert_eq!(treasury_balance, expected_fee);
+}
@@ -0,0 +1,322 @@
+//! # Test Harness
+//!
+//! Shared helpers for wiring up the Registry and Escrow contracts in
+//! `cw-multi-test` and driving the common execute paths used across the
+//! escrow test suite.
+
+use cosmwasm_std::{to_json_binary, Addr, Coin, Empty, Uint128};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+
+use crate::msg::{Cw20HookMsg, ExecuteMsg, InstantiateMsg as EscrowInstantiateMsg};
+
+pub const NEUTRON: &str = "untrn";
+pub const DEFAULT_TOOL_ID: &str = "demo-tool";
+pub const DEFAULT_MAX_FEE: u128 = 1_000_000;
+pub const DEFAULT_TTL: u64 = 100;
+pub const USER: &str = "user";
+pub const PROVIDER: &str = "provider";
+pub const TREASURY: &str = "treasury";
+
+pub struct Contracts {
+    pub app: App,
+    pub registry_addr: String,
+    pub escrow_addr: String,
+}
+
+fn escrow_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )
+        .with_sudo(crate::contract::sudo),
+    )
+}
+
+fn registry_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        registry::contract::execute,
+        registry::contract::instantiate,
+        registry::contract::query,
+    ))
+}
+
+fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    ))
+}
+
+/// Instantiates Registry and Escrow against a fresh `App`, funding the default test user.
+pub fn setup_contracts() -> Contracts {
+    setup_contracts_with_fee(0)
+}
+
+/// Same as `setup_contracts`, but with a protocol fee configured on the Escrow contract.
+pub fn setup_contracts_with_fee(fee_bps: u16) -> Contracts {
+    let mut app = AppBuilder::new().build(|router, api, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &api.addr_make(USER),
+                vec![Coin {
+                    denom: NEUTRON.to_string(),
+                    amount: Uint128::new(10 * DEFAULT_MAX_FEE),
+                }],
+            )
+            .unwrap();
+    });
+
+    let owner = app.api().addr_make("owner");
+
+    let registry_code_id = app.store_code(registry_contract());
+    let registry_addr = app
+        .instantiate_contract(
+            registry_code_id,
+            owner.clone(),
+            &registry::msg::InstantiateMsg {},
+            &[],
+            "registry",
+            None,
+        )
+        .unwrap();
+
+    let escrow_code_id = app.store_code(escrow_contract());
+    let escrow_addr = app
+        .instantiate_contract(
+            escrow_code_id,
+            owner,
+            &EscrowInstantiateMsg {
+                registry_addr: registry_addr.to_string(),
+                fee_bps,
+                treasury: app.api().addr_make(TREASURY).to_string(),
+            },
+            &[],
+            "escrow",
+            None,
+        )
+        .unwrap();
+
+    Contracts {
+        app,
+        registry_addr: registry_addr.to_string(),
+        escrow_addr: escrow_addr.to_string(),
+    }
+}
+
+/// Registers a tool priced in the native `NEUTRON` denom.
+pub fn register_tool(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    price: u128,
+    provider: &str,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &registry::msg::ExecuteMsg::RegisterTool {
+            tool_id: tool_id.to_string(),
+            price: Uint128::new(price),
+            denom: Some(NEUTRON.to_string()),
+            description: "test tool".to_string(),
+            endpoint: "https://example.com".to_string(),
+            category: None,
+        },
+        &[],
+    )
+}
+
+/// Instantiates a cw20-base token, minting `balance` to `holder`. Returns the token's address.
+pub fn setup_cw20(contracts: &mut Contracts, holder: &str, balance: u128) -> String {
+    let owner = contracts.app.api().addr_make("owner");
+    let holder_addr = contracts.app.api().addr_make(holder);
+
+    let code_id = contracts.app.store_code(cw20_contract());
+    contracts
+        .app
+        .instantiate_contract(
+            code_id,
+            owner,
+            &cw20_base::msg::InstantiateMsg {
+                name: "Test Token".to_string(),
+                symbol: "TEST".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: holder_addr.to_string(),
+                    amount: Uint128::new(balance),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20",
+            None,
+        )
+        .unwrap()
+        .to_string()
+}
+
+/// Registers a tool priced in the CW20 token at `cw20_addr`.
+pub fn register_cw20_tool(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    price: u128,
+    cw20_addr: &str,
+    provider: &str,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &registry::msg::ExecuteMsg::RegisterTool {
+            tool_id: tool_id.to_string(),
+            price: Uint128::new(price),
+            denom: Some(format!("cw20:{cw20_addr}")),
+            description: "test tool".to_string(),
+            endpoint: "https://example.com".to_string(),
+            category: None,
+        },
+        &[],
+    )
+}
+
+/// Locks funds for `tool_id` by sending `max_fee` of `cw20_addr` via `Cw20ExecuteMsg::Send`,
+/// as `user`, and returns the resulting escrow ID.
+pub fn lock_funds_cw20(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    max_fee: u128,
+    ttl: u64,
+    auth_token: String,
+    user: &str,
+    cw20_addr: &str,
+) -> anyhow::Result<u64> {
+    let user_addr = contracts.app.api().addr_make(user);
+    let expires = contracts.app.block_info().height + ttl;
+
+    let hook_msg = Cw20HookMsg::LockFunds {
+        tool_id: tool_id.to_string(),
+        max_fee: Uint128::new(max_fee),
+        expires,
+        auth_token,
+        arbiter: None,
+    };
+
+    let response = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(cw20_addr),
+        &Cw20ExecuteMsg::Send {
+            contract: contracts.escrow_addr.clone(),
+            amount: Uint128::new(max_fee),
+            msg: to_json_binary(&hook_msg)?,
+        },
+        &[],
+    )?;
+
+    let escrow_id = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "escrow_id")
+        .expect("escrow_id attribute missing from response")
+        .value
+        .parse()
+        .expect("escrow_id attribute is not a valid u64");
+
+    Ok(escrow_id)
+}
+
+/// Locks native funds for `tool_id` and returns the resulting escrow ID.
+pub fn lock_funds(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    max_fee: u128,
+    ttl: u64,
+    auth_token: String,
+    user: &str,
+    funds: &[Coin],
+) -> anyhow::Result<u64> {
+    let user_addr = contracts.app.api().addr_make(user);
+    let expires = contracts.app.block_info().height + ttl;
+
+    let response = contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::LockFunds {
+            tool_id: tool_id.to_string(),
+            max_fee: Uint128::new(max_fee),
+            expires,
+            auth_token,
+            arbiter: None,
+        },
+        funds,
+    )?;
+
+    let escrow_id = response
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "escrow_id")
+        .expect("escrow_id attribute missing from response")
+        .value
+        .parse()
+        .expect("escrow_id attribute is not a valid u64");
+
+    Ok(escrow_id)
+}
+
+/// Releases `usage_fee` from an escrow to its provider, as the provider.
+pub fn release(
+    contracts: &mut Contracts,
+    escrow_id: u64,
+    usage_fee: u128,
+    provider: &str,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Release {
+            escrow_id,
+            usage_fee: Uint128::new(usage_fee),
+        },
+        &[],
+    )
+}
+
+/// Closes out an escrow as `provider`, crediting whatever `max_fee` the milestone
+/// `Release` calls didn't draw down back to the user.
+pub fn finalize(
+    contracts: &mut Contracts,
+    escrow_id: u64,
+    provider: &str,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::Finalize { escrow_id },
+        &[],
+    )
+}
+
+/// Refunds an expired escrow back to `user`.
+pub fn refund_expired(
+    contracts: &mut Contracts,
+    escrow_id: u64,
+    user: &str,
+) -> anyhow::Result<AppResponse> {
+    let user_addr = contracts.app.api().addr_make(user);
+    contracts.app.execute_contract(
+        user_addr,
+        Addr::unchecked(&contracts.escrow_addr),
+        &ExecuteMsg::RefundExpired { escrow_id },
+        &[],
+    )
+}
@@ -1,10 +1,12 @@
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult};
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use enum_iterator::all;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ToolResponse};
-use crate::state::{ToolMeta, TOOLS};
+use crate::msg::{CategoriesResponse, CategoryCount, ExecuteMsg, InstantiateMsg, QueryMsg, ToolResponse};
+use crate::state::{PaymentAsset, ToolCategory, ToolMeta, CATEGORY_ACTIVE_COUNTS, TOOLS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:registry";
@@ -13,6 +15,10 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 // Default denomination for backward compatibility
 const DEFAULT_DENOM: &str = "untrn";
 
+// GetTools pagination cap; `limit: None` means no cap (full scan), matching the original
+// unparameterized behavior.
+const MAX_LIMIT: u32 = 100;
+
 /// Validates endpoint URL format and length
 fn validate_endpoint(endpoint: &str) -> Result<(), ContractError> {
     // Check length constraint (≤ 512 characters)
@@ -28,6 +34,20 @@ fn validate_endpoint(endpoint: &str) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Increments the active-tool count for `category`, used whenever a tool becomes active.
+fn increment_category_count(deps: DepsMut, category: ToolCategory) -> StdResult<()> {
+    let key = category.as_str();
+    let count = CATEGORY_ACTIVE_COUNTS.may_load(deps.storage, key)?.unwrap_or(0);
+    CATEGORY_ACTIVE_COUNTS.save(deps.storage, key, &(count + 1))
+}
+
+/// Decrements the active-tool count for `category`, used whenever a tool becomes inactive.
+fn decrement_category_count(deps: DepsMut, category: ToolCategory) -> StdResult<()> {
+    let key = category.as_str();
+    let count = CATEGORY_ACTIVE_COUNTS.may_load(deps.storage, key)?.unwrap_or(0);
+    CATEGORY_ACTIVE_COUNTS.save(deps.storage, key, &count.saturating_sub(1))
+}
+
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
@@ -52,30 +72,34 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::RegisterTool { tool_id, price, denom, description, endpoint } => 
-            execute_register_tool(deps, info, tool_id, price, denom, description, endpoint),
-        ExecuteMsg::UpdatePrice { tool_id, price } => 
+        ExecuteMsg::RegisterTool { tool_id, price, denom, description, endpoint, category } =>
+            execute_register_tool(deps, info, tool_id, price, denom, description, endpoint, category),
+        ExecuteMsg::UpdatePrice { tool_id, price } =>
             execute_update_price(deps, info, tool_id, price),
-        ExecuteMsg::PauseTool { tool_id } => 
+        ExecuteMsg::PauseTool { tool_id } =>
             execute_pause_tool(deps, info, tool_id),
-        ExecuteMsg::ResumeTool { tool_id } => 
+        ExecuteMsg::ResumeTool { tool_id } =>
             execute_resume_tool(deps, info, tool_id),
-        ExecuteMsg::UpdateDenom { tool_id, denom } => 
+        ExecuteMsg::UpdateDenom { tool_id, denom } =>
             execute_update_denom(deps, info, tool_id, denom),
-        ExecuteMsg::UpdateEndpoint { tool_id, endpoint } => 
+        ExecuteMsg::UpdateEndpoint { tool_id, endpoint } =>
             execute_update_endpoint(deps, info, tool_id, endpoint),
+        ExecuteMsg::UpdateCategory { tool_id, category } =>
+            execute_update_category(deps, info, tool_id, category),
     }
 }
 
 // RegisterTool handler implementation
+#[allow(clippy::too_many_arguments)]
 pub fn execute_register_tool(
-    deps: DepsMut,
+    mut deps: DepsMut,
     info: MessageInfo,
     tool_id: String,
     price: cosmwasm_std::Uint128,
     denom: Option<String>,
     description: String,
     endpoint: String,
+    category: Option<ToolCategory>,
 ) -> Result<Response, ContractError> {
     // Validate tool_id length ≤ 16 characters
     if tool_id.len() > 16 {
@@ -92,22 +116,31 @@ pub fn execute_register_tool(
     
     // Store provider address from info.sender
     let provider = info.sender;
-    
-    // Use provided denom or default to "untrn"
+
+    // Use provided denom or default to "untrn"; a "cw20:<addr>" denom prices the tool in a CW20 token
     let denom = denom.unwrap_or_else(|| DEFAULT_DENOM.to_string());
-    
+    let payment_asset = PaymentAsset::parse(&denom, deps.api)?;
+    let category = category.unwrap_or_default();
+    let previous = TOOLS.may_load(deps.storage, &tool_id)?;
+
     // Store tool metadata in TOOLS map
     let tool = ToolMeta {
         provider: provider.clone(),
         price,
         denom: denom.clone(),
+        payment_asset,
         is_active: true,
         description: description.clone(),
         endpoint: endpoint.clone(),
+        category,
     };
     
     TOOLS.save(deps.storage, &tool_id, &tool)?;
-    
+    if let Some(previous) = previous.filter(|t| t.is_active) {
+        decrement_category_count(deps.branch(), previous.category)?;
+    }
+    increment_category_count(deps, category)?;
+
     // Return success response with tool_id
     Ok(Response::new()
         .add_attribute("method", "register_tool")
@@ -117,7 +150,8 @@ pub fn execute_register_tool(
         .add_attribute("denom", denom)
         .add_attribute("is_active", "true")
         .add_attribute("description", description)
-        .add_attribute("endpoint", endpoint))
+        .add_attribute("endpoint", endpoint)
+        .add_attribute("category", format!("{:?}", category)))
 }
 
 // UpdatePrice handler implementation
@@ -162,9 +196,14 @@ pub fn execute_pause_tool(
     }
     
     // Set is_active to false
+    let was_active = tool.is_active;
     tool.is_active = false;
+    let category = tool.category;
     TOOLS.save(deps.storage, &tool_id, &tool)?;
-    
+    if was_active {
+        decrement_category_count(deps, category)?;
+    }
+
     Ok(Response::new()
         .add_attribute("method", "pause_tool")
         .add_attribute("tool_id", tool_id))
@@ -186,9 +225,14 @@ pub fn execute_resume_tool(
     }
     
     // Set is_active to true
+    let was_active = tool.is_active;
     tool.is_active = true;
+    let category = tool.category;
     TOOLS.save(deps.storage, &tool_id, &tool)?;
-    
+    if !was_active {
+        increment_category_count(deps, category)?;
+    }
+
     Ok(Response::new()
         .add_attribute("method", "resume_tool")
         .add_attribute("tool_id", tool_id))
@@ -210,7 +254,8 @@ pub fn execute_update_denom(
         return Err(ContractError::Unauthorized {});
     }
     
-    // Update denom and save
+    // Update denom (and the derived payment asset) and save
+    tool.payment_asset = PaymentAsset::parse(&denom, deps.api)?;
     tool.denom = denom.clone();
     TOOLS.save(deps.storage, &tool_id, &tool)?;
     
@@ -249,11 +294,50 @@ pub fn execute_update_endpoint(
         .add_attribute("new_endpoint", endpoint))
 }
 
+// UpdateCategory handler implementation
+pub fn execute_update_category(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    tool_id: String,
+    category: ToolCategory,
+) -> Result<Response, ContractError> {
+    // Load existing tool
+    let mut tool = TOOLS.may_load(deps.storage, &tool_id)?
+        .ok_or(ContractError::ToolNotFound {})?;
+
+    // Verify sender is the provider
+    if info.sender != tool.provider {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update category and save
+    let old_category = tool.category;
+    let is_active = tool.is_active;
+    tool.category = category;
+    TOOLS.save(deps.storage, &tool_id, &tool)?;
+    if is_active && old_category != category {
+        decrement_category_count(deps.branch(), old_category)?;
+        increment_category_count(deps, category)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "update_category")
+        .add_attribute("tool_id", tool_id)
+        .add_attribute("new_category", format!("{:?}", category)))
+}
+
 #[entry_point]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetTool { tool_id } => query_tool(deps, tool_id),
-        QueryMsg::GetTools {} => query_all_tools(deps),
+        QueryMsg::GetTools {
+            start_after,
+            limit,
+            active_only,
+            provider,
+            category,
+        } => query_tools(deps, start_after, limit, active_only, provider, category),
+        QueryMsg::GetCategories {} => query_categories(deps),
     }
 }
 
@@ -268,9 +352,11 @@ pub fn query_tool(deps: Deps, tool_id: String) -> StdResult<Binary> {
                 provider: tool_meta.provider.to_string(),
                 price: tool_meta.price,
                 denom: tool_meta.denom,
+                payment_asset: tool_meta.payment_asset,
                 is_active: tool_meta.is_active,
                 description: tool_meta.description,
                 endpoint: tool_meta.endpoint,
+                category: tool_meta.category,
             };
             to_json_binary(&response)
         },
@@ -278,24 +364,72 @@ pub fn query_tool(deps: Deps, tool_id: String) -> StdResult<Binary> {
     }
 }
 
-// GetTools query implementation
-pub fn query_all_tools(deps: Deps) -> StdResult<Binary> {
+// GetTools query implementation: paginated, with optional active-only, provider, and category filters
+pub fn query_tools(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    active_only: Option<bool>,
+    provider: Option<String>,
+    category: Option<ToolCategory>,
+) -> StdResult<Binary> {
+    let limit = limit.map_or(usize::MAX, |l| l.min(MAX_LIMIT) as usize);
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let mut last_tool_id = None;
     let tools: StdResult<Vec<_>> = TOOLS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, tool)) => {
+                let matches_active = active_only != Some(true) || tool.is_active;
+                let matches_provider = provider
+                    .as_ref()
+                    .map(|p| tool.provider.as_str() == p)
+                    .unwrap_or(true);
+                let matches_category = category.map(|c| tool.category == c).unwrap_or(true);
+                matches_active && matches_provider && matches_category
+            }
+            Err(_) => true,
+        })
+        .take(limit)
         .map(|item| {
             let (tool_id, tool_meta) = item?;
+            last_tool_id = Some(tool_id.clone());
             Ok(ToolResponse {
-                tool_id: tool_id.to_string(),
+                tool_id,
                 provider: tool_meta.provider.to_string(),
                 price: tool_meta.price,
                 denom: tool_meta.denom,
+                payment_asset: tool_meta.payment_asset,
                 is_active: tool_meta.is_active,
                 description: tool_meta.description,
                 endpoint: tool_meta.endpoint,
+                category: tool_meta.category,
             })
         })
         .collect();
 
-    let response = crate::msg::ToolsResponse { tools: tools? };
+    let response = crate::msg::ToolsResponse {
+        tools: tools?,
+        last_tool_id,
+    };
     to_json_binary(&response)
+}
+
+// GetCategories query implementation: every known ToolCategory variant, with its active tool
+// count read from CATEGORY_ACTIVE_COUNTS rather than scanning TOOLS.
+pub fn query_categories(deps: Deps) -> StdResult<Binary> {
+    let categories = all::<ToolCategory>()
+        .map(|category| {
+            let active_tool_count = CATEGORY_ACTIVE_COUNTS
+                .may_load(deps.storage, category.as_str())?
+                .unwrap_or(0);
+            Ok(CategoryCount {
+                category,
+                active_tool_count,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&CategoriesResponse { categories })
 }
\ No newline at end of file
@@ -0,0 +1,101 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{PaymentAsset, ToolCategory};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Register a new tool. `denom` defaults to the native `untrn` denom; pass
+    /// `cw20:<contract_addr>` to price the tool in a CW20 token instead. `category`
+    /// defaults to `Other`.
+    RegisterTool {
+        tool_id: String,
+        price: Uint128,
+        denom: Option<String>,
+        description: String,
+        endpoint: String,
+        category: Option<ToolCategory>,
+    },
+    UpdatePrice {
+        tool_id: String,
+        price: Uint128,
+    },
+    PauseTool {
+        tool_id: String,
+    },
+    ResumeTool {
+        tool_id: String,
+    },
+    UpdateDenom {
+        tool_id: String,
+        denom: String,
+    },
+    UpdateEndpoint {
+        tool_id: String,
+        endpoint: String,
+    },
+    UpdateCategory {
+        tool_id: String,
+        category: ToolCategory,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    GetTool {
+        tool_id: String,
+    },
+    /// Paginated, optionally filtered tool listing. Omitting every field scans the whole
+    /// registry, matching the original unparameterized behavior.
+    GetTools {
+        #[serde(default)]
+        start_after: Option<String>,
+        #[serde(default)]
+        limit: Option<u32>,
+        #[serde(default)]
+        active_only: Option<bool>,
+        #[serde(default)]
+        provider: Option<String>,
+        #[serde(default)]
+        category: Option<ToolCategory>,
+    },
+    /// Lists every known category and how many active tools fall into it.
+    GetCategories {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ToolResponse {
+    pub tool_id: String,
+    pub provider: String,
+    pub price: Uint128,
+    pub denom: String,
+    pub payment_asset: PaymentAsset,
+    pub is_active: bool,
+    pub description: String,
+    pub endpoint: String,
+    pub category: ToolCategory,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ToolsResponse {
+    pub tools: Vec<ToolResponse>,
+    /// The `tool_id` of the last entry returned; pass as `start_after` to fetch the next page.
+    pub last_tool_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CategoryCount {
+    pub category: ToolCategory,
+    pub active_tool_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CategoriesResponse {
+    pub categories: Vec<CategoryCount>,
+}
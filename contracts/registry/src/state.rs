@@ -0,0 +1,78 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Map;
+use enum_iterator::Sequence;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A provider-facing taxonomy tools are discoverable by, alongside free-text `description`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, JsonSchema, Sequence)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCategory {
+    Inference,
+    Storage,
+    Data,
+    Compute,
+    Other,
+}
+
+impl Default for ToolCategory {
+    fn default() -> Self {
+        ToolCategory::Other
+    }
+}
+
+impl ToolCategory {
+    /// Stable storage key for `CATEGORY_ACTIVE_COUNTS`, independent of `Debug` formatting.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToolCategory::Inference => "inference",
+            ToolCategory::Storage => "storage",
+            ToolCategory::Data => "data",
+            ToolCategory::Compute => "compute",
+            ToolCategory::Other => "other",
+        }
+    }
+}
+
+/// The asset a tool is priced/paid in: either a native bank denom or a CW20 token contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentAsset {
+    Native(String),
+    Cw20(Addr),
+}
+
+impl PaymentAsset {
+    /// Parses a `ToolMeta::denom` string: a `cw20:<addr>` denom names a CW20 token contract,
+    /// anything else is a native denom.
+    pub fn parse(denom: &str, api: &dyn cosmwasm_std::Api) -> Result<Self, crate::error::ContractError> {
+        match denom.strip_prefix("cw20:") {
+            Some(addr) => {
+                let validated = api
+                    .addr_validate(addr)
+                    .map_err(|_| crate::error::ContractError::InvalidCw20Address(addr.to_string()))?;
+                Ok(PaymentAsset::Cw20(validated))
+            }
+            None => Ok(PaymentAsset::Native(denom.to_string())),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ToolMeta {
+    pub provider: Addr,
+    pub price: cosmwasm_std::Uint128,
+    pub denom: String,
+    pub payment_asset: PaymentAsset,
+    pub is_active: bool,
+    pub description: String,
+    pub endpoint: String,
+    pub category: ToolCategory,
+}
+
+pub const TOOLS: Map<&str, ToolMeta> = Map::new("tools");
+
+/// Number of active tools per category, keyed by `ToolCategory::as_str()`. Kept up to date by
+/// the register/pause/resume/update-category handlers so `GetCategories` never has to scan
+/// `TOOLS` on read.
+pub const CATEGORY_ACTIVE_COUNTS: Map<&str, u64> = Map::new("category_active_counts");
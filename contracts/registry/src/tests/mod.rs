@@ -0,0 +1,4 @@
+mod setup_contract;
+
+mod categories_test;
+mod pagination_test;
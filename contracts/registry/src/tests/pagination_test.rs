@@ -0,0 +1,102 @@
+//! # GetTools Pagination and Filtering Test
+//!
+//! This module tests `GetTools`'s pagination (`start_after`/`limit`/`last_tool_id`) and its
+//! `active_only`/`provider`/`category` filters.
+//!
+//! ## Test Coverage
+//!
+//! This test verifies that:
+//! 1. Omitting `limit` returns every registered tool in one page (no hidden cap)
+//! 2. Paging through with `limit` and `last_tool_id` as the next `start_after` visits every tool
+//!    exactly once
+//! 3. `active_only: true` excludes paused tools
+//! 4. `provider` filters to that provider's tools only
+//! 5. `category` filters to that category's tools only
+
+use crate::state::ToolCategory;
+use crate::tests::setup_contract::{
+    get_tools, pause_tool, register_tool, setup_contracts, PROVIDER,
+};
+
+const OTHER_PROVIDER: &str = "other_provider";
+
+/// # Test: Omitting `limit` Returns the Whole Registry
+///
+/// This is a regression test for the pagination back-compat requirement: a
+/// `GetTools {}` call with every field omitted must behave like the original
+/// unparameterized listing, not silently cap at some default page size.
+#[test]
+fn test_no_limit_returns_everything() {
+    let mut contracts = setup_contracts();
+    for i in 0..15 {
+        register_tool(&mut contracts, &format!("tool-{i:02}"), 100, None, PROVIDER, None).unwrap();
+    }
+
+    let response = get_tools(&contracts, None, None, None, None, None);
+    assert_eq!(response.tools.len(), 15);
+}
+
+/// # Test: Paging Through With `limit` and `last_tool_id` Visits Every Tool Once
+#[test]
+fn test_pagination_walks_all_pages() {
+    let mut contracts = setup_contracts();
+    for i in 0..15 {
+        register_tool(&mut contracts, &format!("tool-{i:02}"), 100, None, PROVIDER, None).unwrap();
+    }
+
+    let mut seen = Vec::new();
+    let mut start_after: Option<String> = None;
+    loop {
+        let response = get_tools(&contracts, start_after.as_deref(), Some(5), None, None, None);
+        if response.tools.is_empty() {
+            break;
+        }
+        seen.extend(response.tools.iter().map(|t| t.tool_id.clone()));
+        start_after = response.last_tool_id;
+        if seen.len() >= 15 {
+            break;
+        }
+    }
+
+    seen.sort();
+    let expected: Vec<String> = (0..15).map(|i| format!("tool-{i:02}")).collect();
+    assert_eq!(seen, expected);
+}
+
+/// # Test: `active_only` Excludes Paused Tools
+#[test]
+fn test_active_only_filter() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, "active-tool", 100, None, PROVIDER, None).unwrap();
+    register_tool(&mut contracts, "paused-tool", 100, None, PROVIDER, None).unwrap();
+    pause_tool(&mut contracts, "paused-tool", PROVIDER).unwrap();
+
+    let response = get_tools(&contracts, None, None, Some(true), None, None);
+    assert_eq!(response.tools.len(), 1);
+    assert_eq!(response.tools[0].tool_id, "active-tool");
+}
+
+/// # Test: `provider` Filters to That Provider's Tools Only
+#[test]
+fn test_provider_filter() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, "mine", 100, None, PROVIDER, None).unwrap();
+    register_tool(&mut contracts, "theirs", 100, None, OTHER_PROVIDER, None).unwrap();
+
+    let provider_addr = contracts.app.api().addr_make(PROVIDER).to_string();
+    let response = get_tools(&contracts, None, None, None, Some(&provider_addr), None);
+    assert_eq!(response.tools.len(), 1);
+    assert_eq!(response.tools[0].tool_id, "mine");
+}
+
+/// # Test: `category` Filters to That Category's Tools Only
+#[test]
+fn test_category_filter() {
+    let mut contracts = setup_contracts();
+    register_tool(&mut contracts, "inference-tool", 100, None, PROVIDER, Some(ToolCategory::Inference)).unwrap();
+    register_tool(&mut contracts, "storage-tool", 100, None, PROVIDER, Some(ToolCategory::Storage)).unwrap();
+
+    let response = get_tools(&contracts, None, None, None, None, Some(ToolCategory::Storage));
+    assert_eq!(response.tools.len(), 1);
+    assert_eq!(response.tools[0].tool_id, "storage-tool");
+}
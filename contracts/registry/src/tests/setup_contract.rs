@@ -0,0 +1,157 @@
+//! # Test Harness
+//!
+//! Shared helpers for wiring up the Registry contract in `cw-multi-test` and
+//! driving the common execute/query paths used across the registry test suite.
+
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+use crate::msg::{
+    CategoriesResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ToolResponse, ToolsResponse,
+};
+use crate::state::ToolCategory;
+
+pub const PROVIDER: &str = "provider";
+
+pub struct Contracts {
+    pub app: App,
+    pub registry_addr: String,
+}
+
+fn registry_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    ))
+}
+
+/// Instantiates the Registry contract against a fresh `App`.
+pub fn setup_contracts() -> Contracts {
+    let mut app = App::default();
+    let owner = app.api().addr_make("owner");
+
+    let code_id = app.store_code(registry_contract());
+    let registry_addr = app
+        .instantiate_contract(code_id, owner, &InstantiateMsg {}, &[], "registry", None)
+        .unwrap();
+
+    Contracts {
+        app,
+        registry_addr: registry_addr.to_string(),
+    }
+}
+
+/// Registers `tool_id`, priced in the native `untrn` denom unless `denom` is given.
+#[allow(clippy::too_many_arguments)]
+pub fn register_tool(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    price: u128,
+    denom: Option<&str>,
+    provider: &str,
+    category: Option<ToolCategory>,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &ExecuteMsg::RegisterTool {
+            tool_id: tool_id.to_string(),
+            price: Uint128::new(price),
+            denom: denom.map(|d| d.to_string()),
+            description: "test tool".to_string(),
+            endpoint: "https://example.com".to_string(),
+            category,
+        },
+        &[],
+    )
+}
+
+pub fn pause_tool(contracts: &mut Contracts, tool_id: &str, provider: &str) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &ExecuteMsg::PauseTool {
+            tool_id: tool_id.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn resume_tool(contracts: &mut Contracts, tool_id: &str, provider: &str) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &ExecuteMsg::ResumeTool {
+            tool_id: tool_id.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn update_category(
+    contracts: &mut Contracts,
+    tool_id: &str,
+    provider: &str,
+    category: ToolCategory,
+) -> anyhow::Result<AppResponse> {
+    let provider_addr = contracts.app.api().addr_make(provider);
+    contracts.app.execute_contract(
+        provider_addr,
+        Addr::unchecked(&contracts.registry_addr),
+        &ExecuteMsg::UpdateCategory {
+            tool_id: tool_id.to_string(),
+            category,
+        },
+        &[],
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_tools(
+    contracts: &Contracts,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+    active_only: Option<bool>,
+    provider: Option<&str>,
+    category: Option<ToolCategory>,
+) -> ToolsResponse {
+    contracts
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &contracts.registry_addr,
+            &QueryMsg::GetTools {
+                start_after: start_after.map(|s| s.to_string()),
+                limit,
+                active_only,
+                provider: provider.map(|p| p.to_string()),
+                category,
+            },
+        )
+        .unwrap()
+}
+
+pub fn get_tool(contracts: &Contracts, tool_id: &str) -> Option<ToolResponse> {
+    contracts
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &contracts.registry_addr,
+            &QueryMsg::GetTool {
+                tool_id: tool_id.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub fn get_categories(contracts: &Contracts) -> CategoriesResponse {
+    contracts
+        .app
+        .wrap()
+        .query_wasm_smart(&contracts.registry_addr, &QueryMsg::GetCategories {})
+        .unwrap()
+}